@@ -2,12 +2,12 @@
 //!
 //! Derive `Partial` on a struct `Foo` to generate a new struct named `FooPartial`
 //! where every field type is wrapped in `Option<T>` unless it is already an `Option<T>`.
-//! Only structs with named fields are accepted; tuple and unit structs are not supported **yet**.
+//! Named-field structs, tuple structs, unit structs and enums are all supported.
 //!
 //! Example:
 //! ```ignore
-//! #[optifier::partial_derive(Debug, Clone)]
 //! #[derive(optifier::Partial)]
+//! #[partial_derive(Debug, Clone)]
 //! pub struct Foo {
 //!     a: i32,
 //!     b: Option<String>,
@@ -24,8 +24,120 @@
 //! }
 //! ```
 //!
-//! The `#[optifier::partial_derive(...)]` attribute controls which traits are derived for the
-//! generated `*Partial` type. It accepts a comma-separated list of trait paths.
+//! The `#[partial_derive(...)]` attribute controls which traits are implemented for the generated
+//! `*Partial` type. It accepts a comma-separated list of trait names. The expansion above is
+//! simplified: `Debug` and `Clone` are hand-implemented with tailored bounds rather than derived,
+//! see below.
+//!
+//! `FooPartial` also gets a fluent builder API: `FooPartial::new()` starts with every field set
+//! to `None`, and one chainable setter per field (named after the field) takes the *unwrapped*
+//! value and stores it as `Some(value)`:
+//! ```ignore
+//! let partial = FooPartial::new().a(42).c(vec![1, 2, 3]);
+//! let foo: Foo = partial.try_into()?;
+//! ```
+//!
+//! Individual fields can be tuned with a `#[optifier(...)]` attribute:
+//! - `skip`: the field is omitted from the generated partial entirely. `TryFrom` always takes the
+//!   original's value via `Default::default()`, so the original field type must implement
+//!   `Default`.
+//! - `rename = "..."`: the field is named differently on the generated partial type.
+//! - `required`: force a `*Missing` error in `TryFrom` when the partial field is absent, even if
+//!   the original field is already `Option<T>`.
+//! - `default = expr`: if the partial field is absent in `TryFrom`, fall back to `expr` instead of
+//!   erroring.
+//! - `merge = "or" | "replace" | "extend"`: how `merge` combines two `Some` values of this field
+//!   (default `"or"`, first-present wins). `"replace"` always takes `other` when it is `Some`.
+//!   `"extend"` concatenates both sides when both are `Some` (for `Option<Vec<_>>`,
+//!   `Option<HashMap<_, _>>` and similar), otherwise keeps whichever side is `Some`.
+//! - `custom = path`: call `path(a, b) -> T` when both sides are `Some`, otherwise keep whichever
+//!   side is `Some`. Takes precedence over `merge = "..."` if both are given.
+//! ```ignore
+//! #[derive(optifier::Partial)]
+//! pub struct Foo {
+//!     #[optifier(skip)]
+//!     cache: Vec<u8>,
+//!     #[optifier(rename = "identifier")]
+//!     id: i32,
+//!     #[optifier(required)]
+//!     name: Option<String>,
+//!     #[optifier(default = 8080)]
+//!     port: u16,
+//!     #[optifier(merge = "replace")]
+//!     override_flag: bool,
+//!     #[optifier(merge = "extend")]
+//!     tags: Vec<String>,
+//!     #[optifier(custom = max_u16)]
+//!     priority: u16,
+//! }
+//!
+//! fn max_u16(a: u16, b: u16) -> u16 {
+//!     a.max(b)
+//! }
+//! ```
+//!
+//! Tuple structs, unit structs and enums are supported too:
+//! ```ignore
+//! #[derive(optifier::Partial)]
+//! struct Point(i32, i32);
+//! // -> struct PointPartial(Option<i32>, Option<i32>);
+//!
+//! #[derive(optifier::Partial)]
+//! struct Marker;
+//! // -> struct MarkerPartial;
+//!
+//! #[derive(optifier::Partial)]
+//! enum Shape {
+//!     Circle { radius: f64 },
+//!     Rect(f64, f64),
+//!     Point,
+//! }
+//! // -> enum ShapePartial {
+//! //        Circle { radius: Option<f64> },
+//! //        Rect(Option<f64>, Option<f64>),
+//! //        Point,
+//! //    }
+//! ```
+//! For enums, `merge` only combines two values of the *same* variant field-by-field; if `self`
+//! and `other` are different variants, `self` wins, mirroring the first-present-wins behaviour of
+//! `Option::or`. The fluent builder API from above is only generated for named-field structs, since
+//! positional/variant-based construction does not map onto a single chain of named setters.
+//! `#[optifier(...)]` field attributes are likewise only honored on named-field structs for now.
+//!
+//! A field can be marked `#[optifier(nested)]` when its type itself derives `Partial`. Instead of
+//! wrapping the field in `Option<Bar>`, the partial gets `Option<BarPartial>`, and `merge` recurses
+//! into the inner partials instead of taking the first `Some`:
+//! ```ignore
+//! #[derive(optifier::Partial)]
+//! struct Base {
+//!     #[optifier(nested)]
+//!     server: Server,
+//! }
+//! // -> struct BasePartial { server: Option<ServerPartial> }
+//! ```
+//! A `None` outer partial means "inherit this sub-config entirely from the other layer" — `merge`
+//! only combines two `Some(..)` values, and otherwise keeps whichever side is `Some`. `TryFrom`
+//! recurses via `BarPartial::try_into()` and bubbles up `BarPartialError` through a dedicated
+//! `*Invalid` variant on the outer error type.
+//!
+//! `Debug` and `Clone` requested through `#[partial_derive(...)]` are hand-implemented with
+//! *tailored* bounds instead of plain `#[derive(...)]`: a generic parameter `T` only gets a
+//! `T: Debug` / `T: Clone` bound if it actually appears in one of the partial's fields, and
+//! `PhantomData<T>` fields don't count as a use of `T`. This avoids the classic derive-macro
+//! over-bounding problem. Other traits passed to `partial_derive` fall back to a plain
+//! `#[derive(...)]` on the generated type, with the usual (unconditional) bounds.
+//!
+//! `#[partial_bound(...)]` is an escape hatch for when the inferred bounds are wrong: put it
+//! after `#[derive(optifier::Partial)]` with explicit predicates, and those are used verbatim for
+//! `Debug`/`Clone` instead of the inferred ones. Like `#[partial_derive(...)]`, it's a
+//! derive-helper attribute, so it must come *after* the `#[derive(...)]` that introduces it:
+//! ```ignore
+//! #[derive(optifier::Partial)]
+//! #[partial_bound(T: Clone)]
+//! struct Foo<T> {
+//!     a: ::std::marker::PhantomData<T>,
+//! }
+//! ```
 
 extern crate proc_macro;
 
@@ -33,118 +145,222 @@ use convert_case::{Case, Casing};
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
 use syn::{
-    Data, DeriveInput, FieldsNamed, Generics, Ident, ImplGenerics, Path, PathArguments, Type,
-    TypeGenerics, TypePath, Visibility, WhereClause, parse_macro_input,
+    Data, DeriveInput, Expr, Field, Fields, FieldsNamed, FieldsUnnamed, Generics, Ident,
+    ImplGenerics, LitStr, Path, PathArguments, Type, TypeGenerics, TypePath, Visibility,
+    WhereClause, WherePredicate, parse_macro_input, punctuated::Punctuated, token::Comma,
+    visit::Visit,
 };
 
-/// Derive macro to generate a `*Partial` variant of a struct with all fields wrapped in `Option`.
+/// Derive macro to generate a `*Partial` variant of a type with all fields wrapped in `Option`.
 ///
-/// Put `#[derive(optifier::Partial)]` on a struct item. The macro will output:
-/// - A new struct `<OriginalName>Partial` with the same visibility and field names, but with each
-///   field type wrapped in `Option<T>`, unless it is already `Option<...>`.
+/// Put `#[derive(optifier::Partial)]` on a struct or enum item. The macro will output a new type
+/// `<OriginalName>Partial` with the same visibility, shape and field names, but with each field
+/// type wrapped in `Option<T>`, unless it is already `Option<...>`.
 ///
 /// Supported:
-/// - Named-field structs
-///
-/// Not supported:
+/// - Named-field structs (also get `#[optifier(...)]` field attributes and a fluent builder API)
 /// - Tuple structs
 /// - Unit structs
-/// - Enums
+/// - Enums (named, tuple and unit variants, possibly mixed)
 ///
 /// Notes:
-/// - Generic parameters and lifetimes are copied as-is to the generated `Partial` struct.
+/// - Generic parameters and lifetimes are copied as-is to the generated `Partial` type.
 /// - Field-level visibilities are preserved.
-/// - Field attributes are not copied to the generated struct (to avoid duplicating derives/etc.).
-#[proc_macro_derive(Partial)]
+/// - Field attributes are not copied to the generated type (to avoid duplicating derives/etc.),
+///   except that `#[optifier(...)]` is read and stripped, see the crate-level docs.
+#[proc_macro_derive(Partial, attributes(optifier, partial_derive, partial_bound))]
 pub fn derive_partial(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
 
     let orig_vis: Visibility = input.vis.clone();
     let orig_ident: Ident = input.ident.clone();
     let partial_ident = format_ident!("{}Partial", orig_ident);
-    let maybe_derive_attr = collect_partial_derives(&input);
-
-    let Data::Struct(input_struct) = input.data else {
-        panic!("Optifier supports only struct types");
-    };
+    let (maybe_derive_attr, smart_traits) = collect_partial_derives(&input);
+    let override_predicates = parse_partial_bound(&input);
 
     // Copy generics from original to partial
     let generics = input.generics.clone();
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    // Currently we support only named fields inside a structure.
-    // Support for tuple structs will be added in the future.
-    //
-    // Generate fields for the partial struct by wrapping types in Option if needed
-
-    let syn::Fields::Named(fields) = &input_struct.fields else {
-        panic!("Optifier supports only named fields");
+    let generated_code = match &input.data {
+        Data::Struct(input_struct) => match &input_struct.fields {
+            Fields::Named(fields) => derive_named_struct_partial(
+                &orig_ident,
+                &orig_vis,
+                &partial_ident,
+                fields,
+                &generics,
+                &impl_generics,
+                &ty_generics,
+                where_clause,
+                maybe_derive_attr,
+                &smart_traits,
+                override_predicates.as_ref(),
+            ),
+            Fields::Unnamed(fields) => derive_tuple_struct_partial(
+                &orig_ident,
+                &orig_vis,
+                &partial_ident,
+                fields,
+                &generics,
+                &impl_generics,
+                &ty_generics,
+                where_clause,
+                maybe_derive_attr,
+                &smart_traits,
+                override_predicates.as_ref(),
+            ),
+            Fields::Unit => derive_unit_struct_partial(
+                &orig_ident,
+                &orig_vis,
+                &partial_ident,
+                &generics,
+                &impl_generics,
+                &ty_generics,
+                where_clause,
+                maybe_derive_attr,
+                &smart_traits,
+                override_predicates.as_ref(),
+            ),
+        },
+        Data::Enum(data_enum) => derive_enum_partial(
+            &orig_ident,
+            &orig_vis,
+            &partial_ident,
+            data_enum,
+            &generics,
+            &impl_generics,
+            &ty_generics,
+            where_clause,
+            maybe_derive_attr,
+            &smart_traits,
+            override_predicates.as_ref(),
+        ),
+        Data::Union(_) => panic!("Optifier does not support union types"),
     };
 
+    TokenStream::from(generated_code)
+}
+
+/// Named-field struct path: the original, most-featured code path (per-field
+/// `#[optifier(...)]` attributes, fluent builder API).
+#[allow(clippy::too_many_arguments)]
+fn derive_named_struct_partial(
+    orig_ident: &Ident,
+    orig_vis: &Visibility,
+    partial_ident: &Ident,
+    fields: &FieldsNamed,
+    generics: &Generics,
+    impl_generics: &ImplGenerics,
+    ty_generics: &TypeGenerics,
+    where_clause: Option<&WhereClause>,
+    maybe_derive_attr: proc_macro2::TokenStream,
+    smart_traits: &[&str],
+    override_predicates: Option<&Punctuated<WherePredicate, Comma>>,
+) -> proc_macro2::TokenStream {
+    let field_plans: Vec<FieldPlan> = fields.named.iter().map(FieldPlan::from_field).collect();
+
     let partial_struct_def = construct_partial_struct(
-        &partial_ident,
-        &orig_vis,
-        fields,
-        &generics,
+        partial_ident,
+        orig_vis,
+        &field_plans,
+        generics,
         where_clause,
         maybe_derive_attr,
     );
 
     let merge_function_impl_block = construct_merge_impl_block(
-        &partial_ident,
-        fields,
-        &impl_generics,
-        &ty_generics,
+        partial_ident,
+        &field_plans,
+        impl_generics,
+        ty_generics,
         where_clause,
     );
 
     let tryfrom_impl_block = construct_tryfrom_impl_block(
-        &orig_ident,
-        &partial_ident,
-        fields,
-        &impl_generics,
-        &ty_generics,
+        orig_ident,
+        partial_ident,
+        &field_plans,
+        impl_generics,
+        ty_generics,
+        where_clause,
+    );
+
+    let builder_impl_block = construct_builder_impl_block(
+        partial_ident,
+        &field_plans,
+        impl_generics,
+        ty_generics,
+        where_clause,
+    );
+
+    let field_types: Vec<Type> = field_plans
+        .iter()
+        .filter(|p| !p.skip)
+        .map(|p| {
+            if p.nested {
+                nested_type_with_suffix(p.nested_inner_ty(), "Partial")
+            } else {
+                p.orig_ty.clone()
+            }
+        })
+        .collect();
+
+    let debug_fields = field_plans.iter().filter(|p| !p.skip).map(|p| {
+        let f_ident = &p.partial_ident;
+        let f_name_str = f_ident.to_string();
+        quote! { .field(#f_name_str, &self.#f_ident) }
+    });
+    let debug_body = quote! {
+        f.debug_struct(stringify!(#partial_ident))
+            #(#debug_fields)*
+            .finish()
+    };
+
+    let clone_fields = field_plans.iter().filter(|p| !p.skip).map(|p| {
+        let f_ident = &p.partial_ident;
+        quote! { #f_ident: self.#f_ident.clone() }
+    });
+    let clone_body = quote! {
+        Self { #(#clone_fields),* }
+    };
+
+    let smart_derive_impls = build_smart_derive_impls(
+        partial_ident,
+        generics,
+        impl_generics,
+        ty_generics,
         where_clause,
+        &field_types,
+        smart_traits,
+        override_predicates,
+        debug_body,
+        clone_body,
     );
 
     // FIXME: I've got no idea why adding a semicolon after #merge_function_impl_block
     // fixes the compilation error, but it does. Seems to me that semicolon is not required
     // after end of impl block. Need to investigate further.
-    let generated_code = quote! {
+    quote! {
         #partial_struct_def
         #merge_function_impl_block
         #tryfrom_impl_block
-    };
-
-    TokenStream::from(generated_code)
+        #builder_impl_block
+        #smart_derive_impls
+    }
 }
 
-/// Attribute macro to configure derives for the generated `*Partial` type.
-///
-/// Usage:
-/// ```ignore
-/// #[optifier::partial_derive(Debug, Clone)]
-/// #[derive(optifier::Partial)]
-/// struct Foo { /* ... */ }
-/// ```
-///
-/// This will cause the generated `FooPartial` to have:
-/// ```ignore
-/// #[derive(Debug, Clone)]
-/// struct FooPartial { /* ... */ }
-/// ```
-#[proc_macro_attribute]
-pub fn partial_derive(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    // This attribute is intentionally a no-op at expansion time.
-    // The `Partial` derive macro will read the attribute arguments
-    // from the original item via `collect_partial_derives`.
-    item
-}
+/// Traits we hand-implement with tailored bounds instead of handing off to `#[derive(...)]`.
+const SMART_DERIVE_TRAITS: &[&str] = &["Debug", "Clone"];
 
-fn collect_partial_derives(input: &syn::DeriveInput) -> proc_macro2::TokenStream {
-    let mut derives: Vec<proc_macro2::TokenStream> = Vec::new();
+/// Split the paths named in `#[partial_derive(...)]` into the ones we hand-implement
+/// with tailored bounds (`SMART_DERIVE_TRAITS`) and the rest, which still get a plain
+/// `#[derive(...)]` on the generated type with the usual (unconditional) bounds.
+fn collect_partial_derives(input: &syn::DeriveInput) -> (proc_macro2::TokenStream, Vec<&'static str>) {
+    let mut fallback_derives: Vec<proc_macro2::TokenStream> = Vec::new();
+    let mut smart_traits: Vec<&'static str> = Vec::new();
 
-    // Look for: #[optifier::partial_derive(Debug, Clone, ...)]
+    // Look for: #[partial_derive(Debug, Clone, ...)]
     for attr in &input.attrs {
         if !attr.path().is_ident("partial_derive") {
             continue;
@@ -152,19 +368,164 @@ fn collect_partial_derives(input: &syn::DeriveInput) -> proc_macro2::TokenStream
 
         let _ = attr.parse_nested_meta(|meta| {
             let path = &meta.path;
-            derives.push(quote! { #path });
+            if let Some(smart) = SMART_DERIVE_TRAITS.iter().find(|name| path.is_ident(name)) {
+                smart_traits.push(smart);
+            } else {
+                fallback_derives.push(quote! { #path });
+            }
             Ok(())
         });
     }
 
-    if derives.is_empty() {
-        // No #[partial_derive(...)] found -> no derives for the partial type
+    let fallback_attr = if fallback_derives.is_empty() {
+        // No fallback traits requested -> no plain #[derive(...)] for the partial type
+        quote! {}
+    } else {
+        quote! { #[derive( #(#fallback_derives),* )] }
+    };
+
+    (fallback_attr, smart_traits)
+}
+
+/// Parse an optional `#[partial_bound(T: Clone, U: Debug)]` escape-hatch attribute on the
+/// original item. When present, its predicates replace the inferred `SMART_DERIVE_TRAITS` bounds
+/// verbatim instead of being computed from field usage.
+fn parse_partial_bound(input: &syn::DeriveInput) -> Option<Punctuated<WherePredicate, Comma>> {
+    for attr in &input.attrs {
+        if !attr.path().is_ident("partial_bound") {
+            continue;
+        }
+
+        return Some(
+            attr.parse_args_with(Punctuated::<WherePredicate, Comma>::parse_terminated)
+                .unwrap_or_else(|err| panic!("Optifier: invalid #[partial_bound(...)]: {err}")),
+        );
+    }
+
+    None
+}
+
+/// Whether `ty` is literally `PhantomData<...>` (possibly qualified, e.g. `marker::PhantomData<T>`).
+/// Such fields don't "use" their generic parameters for bound-inference purposes.
+fn is_phantom_data_type(ty: &Type) -> bool {
+    matches!(ty, Type::Path(TypePath { path, .. })
+        if path.segments.last().is_some_and(|seg| seg.ident == "PhantomData"))
+}
+
+/// Visitor collecting whether a given identifier occurs anywhere inside a type.
+struct ParamUsageVisitor<'a> {
+    needle: &'a Ident,
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for ParamUsageVisitor<'_> {
+    fn visit_ident(&mut self, ident: &'ast Ident) {
+        if ident == self.needle {
+            self.found = true;
+        }
+    }
+}
+
+fn type_mentions_ident(ty: &Type, needle: &Ident) -> bool {
+    let mut visitor = ParamUsageVisitor { needle, found: false };
+    visitor.visit_type(ty);
+    visitor.found
+}
+
+/// Of `generics`' type parameters, return (in declaration order) the ones that are actually
+/// mentioned in `field_types`, skipping any field whose type is literally `PhantomData<...>`.
+fn compute_used_type_params(generics: &Generics, field_types: &[Type]) -> Vec<Ident> {
+    let relevant_types: Vec<&Type> = field_types
+        .iter()
+        .filter(|ty| !is_phantom_data_type(ty))
+        .collect();
+
+    generics
+        .type_params()
+        .map(|tp| &tp.ident)
+        .filter(|param| relevant_types.iter().any(|ty| type_mentions_ident(ty, param)))
+        .cloned()
+        .collect()
+}
+
+/// Build the `where ...` tokens (or nothing, if there are no predicates at all) for a single
+/// hand-implemented trait impl: the original item's own `where` predicates, plus either the
+/// `#[partial_bound(...)]` override verbatim, or one `#param: #trait_path` bound per type
+/// parameter in `used_params`.
+fn tailored_where_tokens(
+    orig_where: Option<&WhereClause>,
+    used_params: &[Ident],
+    trait_path: &proc_macro2::TokenStream,
+    override_predicates: Option<&Punctuated<WherePredicate, Comma>>,
+) -> proc_macro2::TokenStream {
+    let mut predicates: Vec<proc_macro2::TokenStream> = orig_where
+        .map(|w| w.predicates.iter().map(|p| quote! { #p }).collect())
+        .unwrap_or_default();
+
+    match override_predicates {
+        Some(overrides) => predicates.extend(overrides.iter().map(|p| quote! { #p })),
+        None => predicates.extend(used_params.iter().map(|param| quote! { #param: #trait_path })),
+    }
+
+    if predicates.is_empty() {
         quote! {}
     } else {
-        quote! { #[derive( #(#derives),* )] }
+        quote! { where #(#predicates),* }
     }
 }
 
+/// Emit hand-rolled `impl Debug`/`impl Clone` blocks (one each, as requested in `smart_traits`)
+/// for a generated `*Partial` type, with bounds tailored per [`tailored_where_tokens`].
+///
+/// `field_types` drives bound inference: it should be the type of every field the generated
+/// partial carries, before the partial's own `Option<...>` wrapping (e.g. the inner `*Partial`
+/// type for `#[optifier(nested)]` fields), so a generic parameter unused by the partial doesn't
+/// pick up a spurious bound and `PhantomData<T>` fields are still recognised as such.
+#[allow(clippy::too_many_arguments)]
+fn build_smart_derive_impls(
+    partial_ident: &Ident,
+    generics: &Generics,
+    impl_generics: &ImplGenerics,
+    ty_generics: &TypeGenerics,
+    where_clause: Option<&WhereClause>,
+    field_types: &[Type],
+    smart_traits: &[&str],
+    override_predicates: Option<&Punctuated<WherePredicate, Comma>>,
+    debug_body: proc_macro2::TokenStream,
+    clone_body: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let used_params = compute_used_type_params(generics, field_types);
+    let mut impls = Vec::new();
+
+    if smart_traits.contains(&"Debug") {
+        let trait_path = quote! { ::std::fmt::Debug };
+        let bound_where =
+            tailored_where_tokens(where_clause, &used_params, &trait_path, override_predicates);
+        impls.push(quote! {
+            impl #impl_generics ::std::fmt::Debug for #partial_ident #ty_generics #bound_where {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    #debug_body
+                }
+            }
+        });
+    }
+
+    if smart_traits.contains(&"Clone") {
+        let trait_path = quote! { ::std::clone::Clone };
+        let bound_where =
+            tailored_where_tokens(where_clause, &used_params, &trait_path, override_predicates);
+        impls.push(quote! {
+            impl #impl_generics ::std::clone::Clone for #partial_ident #ty_generics #bound_where {
+                fn clone(&self) -> Self {
+                    #clone_body
+                }
+            }
+        });
+    }
+
+    quote! { #(#impls)* }
+}
+
 /// Detect whether a given type is `Option<...>`
 ///
 /// Heuristic:
@@ -186,22 +547,245 @@ fn is_path_option(path: &Path) -> bool {
     }
 }
 
+/// If `ty` is `Option<T>`, return `T`. Otherwise return `None`.
+fn extract_option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(TypePath { path, .. }) = ty else {
+        return None;
+    };
+    let last = path.segments.last()?;
+    if last.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None;
+    };
+    args.args.iter().find_map(|arg| match arg {
+        syn::GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    })
+}
+
+/// How `merge` combines two `Some` values of a single field. Selected via
+/// `#[optifier(merge = "...")]` / `#[optifier(custom = path)]`; see [`FieldAttrs`].
+#[derive(Default)]
+enum MergeStrategy {
+    /// First-present wins: `self.field.or(other.field)`. The default when no strategy is given.
+    #[default]
+    Or,
+    /// `other` always wins when it is `Some`, regardless of `self`.
+    Replace,
+    /// Concatenate both collections when both are `Some` (e.g. `Option<Vec<T>>`,
+    /// `Option<HashMap<K, V>>`), otherwise take whichever side is `Some`.
+    Extend,
+    /// Call the given `fn(T, T) -> T` when both sides are `Some`, otherwise take whichever side
+    /// is `Some`.
+    Custom(Path),
+}
+
+/// Parsed contents of a field's `#[optifier(...)]` attribute.
+#[derive(Default)]
+struct FieldAttrs {
+    skip: bool,
+    rename: Option<Ident>,
+    required: bool,
+    default: Option<Expr>,
+    nested: bool,
+    merge: Option<LitStr>,
+    custom: Option<Path>,
+}
+
+/// Parse the (at most one, but possibly repeated) `#[optifier(...)]` attribute on a field.
+///
+/// Supported keys: `skip`, `rename = "..."`, `required`, `default = expr`, `nested`,
+/// `merge = "or" | "replace" | "extend"`, `custom = path`. Unknown keys are rejected by
+/// `parse_nested_meta`; the error is intentionally swallowed so a stray attribute does not
+/// hard-panic the whole derive, mirroring `collect_partial_derives`.
+fn parse_field_attrs(field: &Field) -> FieldAttrs {
+    let mut attrs = FieldAttrs::default();
+
+    for attr in &field.attrs {
+        if !attr.path().is_ident("optifier") {
+            continue;
+        }
+
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                attrs.skip = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("required") {
+                attrs.required = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("nested") {
+                attrs.nested = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("rename") {
+                let lit: LitStr = meta.value()?.parse()?;
+                attrs.rename = Some(format_ident!("{}", lit.value()));
+                return Ok(());
+            }
+
+            if meta.path.is_ident("default") {
+                let expr: Expr = meta.value()?.parse()?;
+                attrs.default = Some(expr);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("merge") {
+                let lit: LitStr = meta.value()?.parse()?;
+                attrs.merge = Some(lit);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("custom") {
+                let path: Path = meta.value()?.parse()?;
+                attrs.custom = Some(path);
+                return Ok(());
+            }
+
+            Err(meta.error("unsupported `#[optifier(...)]` field attribute"))
+        });
+    }
+
+    attrs
+}
+
+/// A named field plus its resolved `#[optifier(...)]` configuration, computed once per field and
+/// threaded through the `construct_*` functions below so they no longer need to re-derive
+/// per-field behaviour (skip/rename/required/default/nested) from scratch.
+struct FieldPlan<'a> {
+    vis: &'a Visibility,
+    /// The field's name on the original struct. Errors and `Default::default()` fallbacks are
+    /// always reported/constructed in terms of this name.
+    orig_ident: Ident,
+    /// The field's name on the generated `*Partial` struct, after `rename` is applied.
+    partial_ident: Ident,
+    orig_ty: &'a Type,
+    /// Whether the *original* field type is already `Option<T>`.
+    is_option: bool,
+    skip: bool,
+    required: bool,
+    default: Option<Expr>,
+    /// `#[optifier(nested)]`: the field's type itself derives `Partial`, so the generated field is
+    /// `Option<InnerPartial>` and `merge`/`TryFrom` recurse instead of treating it as a leaf value.
+    /// Takes precedence over `default`, which is ignored when set together with `nested`.
+    nested: bool,
+    /// How `merge` combines two `Some` values of this field. Ignored for `nested` fields, which
+    /// always recurse regardless of what `#[optifier(merge = ...)]` says.
+    merge_strategy: MergeStrategy,
+}
+
+impl<'a> FieldPlan<'a> {
+    fn from_field(field: &'a Field) -> Self {
+        let orig_ident = field
+            .ident
+            .clone()
+            .expect("Optifier: Named field must have ident");
+        let attrs = parse_field_attrs(field);
+        let partial_ident = attrs.rename.unwrap_or_else(|| orig_ident.clone());
+
+        // `custom = path` takes precedence over `merge = "..."` if both are given.
+        let merge_strategy = if let Some(path) = attrs.custom {
+            MergeStrategy::Custom(path)
+        } else if let Some(lit) = attrs.merge {
+            match lit.value().as_str() {
+                "or" => MergeStrategy::Or,
+                "replace" => MergeStrategy::Replace,
+                "extend" => MergeStrategy::Extend,
+                other => panic!(
+                    "Optifier: unsupported `#[optifier(merge = \"{other}\")]`, expected one of \
+                     \"or\", \"replace\", \"extend\""
+                ),
+            }
+        } else {
+            MergeStrategy::Or
+        };
+
+        FieldPlan {
+            vis: &field.vis,
+            is_option: is_option_type(&field.ty),
+            orig_ty: &field.ty,
+            orig_ident,
+            partial_ident,
+            skip: attrs.skip,
+            required: attrs.required,
+            default: if attrs.nested { None } else { attrs.default },
+            nested: attrs.nested,
+            merge_strategy,
+        }
+    }
+
+    /// The original field's type with one layer of `Option<...>` stripped, i.e. the type that
+    /// actually derives `Partial` for a `#[optifier(nested)]` field.
+    fn nested_inner_ty(&self) -> &Type {
+        extract_option_inner(self.orig_ty).unwrap_or(self.orig_ty)
+    }
+
+    /// Whether `TryFrom` needs a `*Missing` error variant for this field, i.e. there is no
+    /// `default` fallback and the field is either non-`Option` or explicitly `required`.
+    fn needs_error_variant(&self) -> bool {
+        !self.skip && self.default.is_none() && (!self.is_option || self.required)
+    }
+
+    /// The `<FieldName>Missing` variant ident, derived from the field's name on the generated
+    /// `*Partial` struct (i.e. after `rename`) in PascalCase, e.g. "a" -> "AMissing",
+    /// "user_id" -> "UserIdMissing". Uses `partial_ident` rather than `orig_ident` so the error
+    /// references the name callers actually see and type on the partial/builder API.
+    fn error_variant_ident(&self) -> Ident {
+        let pascal = self.partial_ident.to_string().to_case(Case::Pascal);
+        format_ident!("{}Missing", pascal)
+    }
+
+    /// The `<FieldName>Invalid` variant ident used to bubble up a nested `TryFrom` failure.
+    fn nested_invalid_variant_ident(&self) -> Ident {
+        let pascal = self.partial_ident.to_string().to_case(Case::Pascal);
+        format_ident!("{}Invalid", pascal)
+    }
+}
+
+/// Given a field type `Bar` (or `module::Bar<T>`), produce the type with `suffix` appended to its
+/// last path segment's ident, e.g. `Bar` -> `BarPartial` or `module::Bar<T>` -> `module::BarPartial<T>`.
+/// Used for `#[optifier(nested)]` fields to name the inner `*Partial`/`*PartialError` types.
+fn nested_type_with_suffix(ty: &Type, suffix: &str) -> Type {
+    let mut type_path = match ty.clone() {
+        Type::Path(type_path) => type_path,
+        _ => panic!("Optifier: #[optifier(nested)] requires a path type such as `Bar` or `Bar<T>`"),
+    };
+    let last = type_path
+        .path
+        .segments
+        .last_mut()
+        .expect("Optifier: #[optifier(nested)] field type must have at least one path segment");
+    last.ident = format_ident!("{}{}", last.ident, suffix);
+    Type::Path(type_path)
+}
+
 fn construct_partial_struct(
     type_ident: &Ident,
     type_vis: &Visibility,
-    fields_named: &FieldsNamed,
+    field_plans: &[FieldPlan],
     generics: &Generics,
     where_clause: Option<&WhereClause>,
     derive_attrs: proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
-    let partial_fields = fields_named.named.iter().map(|f| {
-        let f_vis = &f.vis;
-        let f_ident = f
-            .ident
-            .as_ref()
-            .expect("Optifier: Named field must have ident");
-        let f_ty = &f.ty;
-        if is_option_type(f_ty) {
+    let partial_fields = field_plans.iter().filter(|p| !p.skip).map(|p| {
+        let f_vis = p.vis;
+        let f_ident = &p.partial_ident;
+
+        if p.nested {
+            let inner_partial_ty = nested_type_with_suffix(p.nested_inner_ty(), "Partial");
+            return quote! {
+                #f_vis #f_ident: ::std::option::Option<#inner_partial_ty>
+            };
+        }
+
+        let f_ty = p.orig_ty;
+        if p.is_option {
             quote! {
                 #f_vis #f_ident: #f_ty
             }
@@ -226,24 +810,56 @@ fn construct_partial_struct(
 
 fn construct_merge_impl_block(
     type_ident: &Ident,
-    fields_named: &FieldsNamed,
+    field_plans: &[FieldPlan],
     impl_generics: &ImplGenerics,
     ty_generics: &TypeGenerics,
     where_clause: Option<&WhereClause>,
 ) -> proc_macro2::TokenStream {
-    let fields_merged = fields_named.named.iter().map(|f| {
-        let f_ident = f
-            .ident
-            .as_ref()
-            .expect("Optifier: Named field must have ident");
+    let fields_merged = field_plans.iter().filter(|p| !p.skip).map(|p| {
+        let f_ident = &p.partial_ident;
 
-        quote! {
-            #f_ident: self.#f_ident.or(other.#f_ident)
+        if p.nested {
+            // A `None` outer partial means "inherit entirely from the other layer": only combine
+            // two `Some(..)` values field-by-field, otherwise keep whichever side is `Some`.
+            return quote! {
+                #f_ident: match (self.#f_ident, other.#f_ident) {
+                    (::std::option::Option::Some(a), ::std::option::Option::Some(b)) => {
+                        ::std::option::Option::Some(a.merge(b))
+                    }
+                    (a, b) => a.or(b),
+                }
+            };
+        }
+
+        match &p.merge_strategy {
+            MergeStrategy::Or => quote! {
+                #f_ident: self.#f_ident.or(other.#f_ident)
+            },
+            MergeStrategy::Replace => quote! {
+                #f_ident: other.#f_ident.or(self.#f_ident)
+            },
+            MergeStrategy::Extend => quote! {
+                #f_ident: match (self.#f_ident, other.#f_ident) {
+                    (::std::option::Option::Some(mut a), ::std::option::Option::Some(b)) => {
+                        ::std::iter::Extend::extend(&mut a, b);
+                        ::std::option::Option::Some(a)
+                    }
+                    (a, b) => a.or(b),
+                }
+            },
+            MergeStrategy::Custom(path) => quote! {
+                #f_ident: match (self.#f_ident, other.#f_ident) {
+                    (::std::option::Option::Some(a), ::std::option::Option::Some(b)) => {
+                        ::std::option::Option::Some(#path(a, b))
+                    }
+                    (a, b) => a.or(b),
+                }
+            },
         }
     });
 
     let merge_function_impl = quote! {
-        pub fn merge(self, other: #type_ident) -> Self {
+        pub fn merge(self, other: #type_ident #ty_generics) -> Self {
             Self {
                 #(#fields_merged),*
             }
@@ -260,12 +876,15 @@ fn construct_merge_impl_block(
 /// Construct the error type and `TryFrom<Partial> for Original` implementation.
 ///
 /// - The error type is `<OriginalName>PartialError`.
-/// - It has one variant per non-`Option` field in the original struct.
-/// - Conversion succeeds only if all non-optional fields are present (`Some`) in the partial.
+/// - It has one `*Missing` variant per field that `FieldPlan::needs_error_variant` (non-`Option`
+///   fields, or fields with `#[optifier(required)]`, that do not have a `default`).
+/// - It has one `*Invalid` variant per `#[optifier(nested)]` field, wrapping that field's
+///   `InnerPartialError` so a nested conversion failure bubbles up through the outer error.
+/// - Conversion succeeds only if all required fields are present and all nested partials convert.
 fn construct_tryfrom_impl_block(
     orig_ident: &Ident,
     partial_ident: &Ident,
-    fields_named: &FieldsNamed,
+    field_plans: &[FieldPlan],
     impl_generics: &ImplGenerics,
     ty_generics: &TypeGenerics,
     where_clause: Option<&WhereClause>,
@@ -273,57 +892,109 @@ fn construct_tryfrom_impl_block(
     // Name of the error type: e.g. FooPartialError
     let error_ident = format_ident!("{}Error", partial_ident);
 
-    // One enum variant per non-Option field, e.g. AMissing, FieldNameMissing, etc.
-    let error_variants = fields_named.named.iter().filter_map(|f| {
-        let f_ident = f
-            .ident
-            .as_ref()
-            .expect("Optifier: Named field must have ident");
-        let f_ty = &f.ty;
+    let error_variants = field_plans.iter().filter(|p| !p.skip).flat_map(|p| {
+        let mut variants = Vec::new();
 
-        if is_option_type(f_ty) {
-            // Original field was already Option<...> → absence is allowed, no error variant
-            return None;
+        if p.needs_error_variant() {
+            let variant_ident = p.error_variant_ident();
+            let f_name_str = p.partial_ident.to_string();
+            variants.push(quote! {
+                #[error("Field `{}` is missing", #f_name_str)]
+                #variant_ident
+            });
         }
 
-        // Variant name: <FieldName>Missing, using PascalCase.
-        // Example: "a" -> "AMissing", "user_id" -> "UserIdMissing".
-        let f_name_str = f_ident.to_string();
-        let f_name_in_pascal_case = f_name_str.to_case(Case::Pascal);
-        let variant_name = format!("{}Missing", f_name_in_pascal_case);
-        let variant_ident = format_ident!("{}", variant_name);
+        if p.nested {
+            let invalid_variant = p.nested_invalid_variant_ident();
+            let inner_error_ty = nested_type_with_suffix(p.nested_inner_ty(), "PartialError");
+            let f_name_str = p.partial_ident.to_string();
+            variants.push(quote! {
+                #[error("Field `{}` is invalid", #f_name_str)]
+                #invalid_variant(#[source] #inner_error_ty)
+            });
+        }
 
-        Some(quote! {
-            #[error("Field `{}` is missing", #f_name_str)]
-            #variant_ident
-        })
+        variants
     });
 
-    // For constructing the original struct, we need, per field:
+    // For constructing the original struct, per field:
     //
-    // - If original type was non-Option: self.field.ok_or(ErrorVariant)?
-    // - If original type was Option: self.field (already Option<T>)
-    let construct_fields = fields_named.named.iter().map(|f| {
-        let f_ident = f
-            .ident
-            .as_ref()
-            .expect("Optifier: Named field must have ident");
-        let f_ty = &f.ty;
+    // - `skip`: always `Default::default()`, the partial never carries this field.
+    // - `nested`: recurse via `InnerPartial::try_into()`, bubbling up `*Invalid` on failure.
+    // - `default = expr`: fall back to `expr` instead of erroring when absent.
+    // - non-Option (or `required`) without a default: `partial.field.ok_or(ErrorVariant)?`,
+    //   re-wrapped in `Some` if the original type was already `Option<T>`.
+    // - Option, not required, no default: `partial.field` as-is.
+    let construct_fields = field_plans.iter().map(|p| {
+        let orig_ident = &p.orig_ident;
+        let partial_ident = &p.partial_ident;
 
-        if is_option_type(f_ty) {
-            // Accept the value as-is
+        if p.skip {
+            return quote! {
+                #orig_ident: ::std::default::Default::default()
+            };
+        }
+
+        if p.nested {
+            let invalid_variant = p.nested_invalid_variant_ident();
+
+            if p.is_option && !p.required {
+                return quote! {
+                    #orig_ident: match partial.#partial_ident {
+                        ::std::option::Option::Some(inner) => ::std::option::Option::Some(
+                            ::std::convert::TryInto::try_into(inner).map_err(#error_ident::#invalid_variant)?,
+                        ),
+                        ::std::option::Option::None => ::std::option::Option::None,
+                    }
+                };
+            }
+
+            let missing_variant = p.error_variant_ident();
+            let unwrap_inner = quote! {
+                partial.#partial_ident.ok_or(#error_ident::#missing_variant)?
+            };
+
+            return if p.is_option {
+                quote! {
+                    #orig_ident: ::std::option::Option::Some(
+                        ::std::convert::TryInto::try_into(#unwrap_inner).map_err(#error_ident::#invalid_variant)?,
+                    )
+                }
+            } else {
+                quote! {
+                    #orig_ident: ::std::convert::TryInto::try_into(#unwrap_inner).map_err(#error_ident::#invalid_variant)?
+                }
+            };
+        }
+
+        if let Some(default) = &p.default {
+            return if p.is_option {
+                quote! {
+                    #orig_ident: ::std::option::Option::Some(partial.#partial_ident.unwrap_or_else(|| #default))
+                }
+            } else {
+                quote! {
+                    #orig_ident: partial.#partial_ident.unwrap_or_else(|| #default)
+                }
+            };
+        }
+
+        if p.is_option && !p.required {
+            return quote! {
+                #orig_ident: partial.#partial_ident
+            };
+        }
+
+        let variant_ident = p.error_variant_ident();
+
+        if p.is_option {
+            // `required`: the original type is Option<T>, but absence must be an error.
             quote! {
-                #f_ident: partial.#f_ident
+                #orig_ident: ::std::option::Option::Some(partial.#partial_ident.ok_or(#error_ident::#variant_ident)?)
             }
         } else {
-            // Reconstruct the variant name in the same way (PascalCase + "Missing")
-            let raw_name = f_ident.to_string();
-            let pascal = raw_name.to_case(Case::Pascal);
-            let variant_name = format!("{}Missing", pascal);
-            let variant_ident = format_ident!("{}", variant_name);
-
             quote! {
-                #f_ident: partial.#f_ident.ok_or(#error_ident::#variant_ident)?
+                #orig_ident: partial.#partial_ident.ok_or(#error_ident::#variant_ident)?
             }
         }
     });
@@ -337,7 +1008,11 @@ fn construct_tryfrom_impl_block(
         }
     };
 
+    // `#error_ident` can end up uninhabited (e.g. every field is `skip`/`default`/already
+    // `Option`), in which case this impl can never actually fail; that's fine, but it trips
+    // clippy's infallible-impl lint.
     let try_from_impl = quote! {
+        #[allow(clippy::infallible_try_from)]
         impl #impl_generics ::std::convert::TryFrom<#partial_ident #ty_generics> for #orig_ident #ty_generics #where_clause {
             type Error = #error_ident;
 
@@ -354,3 +1029,587 @@ fn construct_tryfrom_impl_block(
         #try_from_impl
     }
 }
+
+/// Construct a fluent builder API for the generated `*Partial` type:
+///
+/// - `FooPartial::new()` initializes every field to `None`.
+/// - One chainable setter per field, named after the (possibly renamed) partial field, taking the
+///   *unwrapped* value and storing it as `Some(value)`. For fields already `Option<T>` in the
+///   original struct, the setter still takes `T` and wraps it. `#[optifier(skip)]` fields have no
+///   setter, since they do not exist on the partial.
+fn construct_builder_impl_block(
+    type_ident: &Ident,
+    field_plans: &[FieldPlan],
+    impl_generics: &ImplGenerics,
+    ty_generics: &TypeGenerics,
+    where_clause: Option<&WhereClause>,
+) -> proc_macro2::TokenStream {
+    let present_fields = field_plans.iter().filter(|p| !p.skip);
+
+    let new_fields = present_fields.clone().map(|p| {
+        let f_ident = &p.partial_ident;
+
+        quote! {
+            #f_ident: ::std::option::Option::None
+        }
+    });
+
+    let setters = present_fields.map(|p| {
+        let f_ident = &p.partial_ident;
+
+        if p.nested {
+            // The partial field is Option<InnerPartial>, so the setter takes InnerPartial.
+            let setter_ty = nested_type_with_suffix(p.nested_inner_ty(), "Partial");
+            return quote! {
+                pub fn #f_ident(mut self, value: #setter_ty) -> Self {
+                    self.#f_ident = ::std::option::Option::Some(value);
+                    self
+                }
+            };
+        }
+
+        let f_ty = p.orig_ty;
+        // For fields already Option<T>, the setter takes the unwrapped T.
+        let setter_ty = extract_option_inner(f_ty).unwrap_or(f_ty);
+
+        quote! {
+            pub fn #f_ident(mut self, value: #setter_ty) -> Self {
+                self.#f_ident = ::std::option::Option::Some(value);
+                self
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics #type_ident #ty_generics #where_clause {
+            pub fn new() -> Self {
+                Self {
+                    #(#new_fields),*
+                }
+            }
+
+            #(#setters)*
+        }
+    }
+}
+
+/// Tuple-struct path: each positional field is independently wrapped in `Option<T>`, and the
+/// generated `*PartialError` gets `Field0Missing`, `Field1Missing`, ... variants for each
+/// non-`Option` position. No `#[optifier(...)]` attributes or builder API: there is no field name
+/// to hang either of them off of.
+#[allow(clippy::too_many_arguments)]
+fn derive_tuple_struct_partial(
+    orig_ident: &Ident,
+    orig_vis: &Visibility,
+    partial_ident: &Ident,
+    fields: &FieldsUnnamed,
+    generics: &Generics,
+    impl_generics: &ImplGenerics,
+    ty_generics: &TypeGenerics,
+    where_clause: Option<&WhereClause>,
+    maybe_derive_attr: proc_macro2::TokenStream,
+    smart_traits: &[&str],
+    override_predicates: Option<&Punctuated<WherePredicate, Comma>>,
+) -> proc_macro2::TokenStream {
+    let partial_fields = fields.unnamed.iter().map(|f| {
+        let f_ty = &f.ty;
+        if is_option_type(f_ty) {
+            quote! { #f_ty }
+        } else {
+            quote! { ::std::option::Option<#f_ty> }
+        }
+    });
+
+    let partial_struct_def = quote! {
+        #maybe_derive_attr
+        #orig_vis struct #partial_ident #generics (#(#partial_fields),*) #where_clause;
+    };
+
+    let indices: Vec<syn::Index> = (0..fields.unnamed.len()).map(syn::Index::from).collect();
+    let merged_fields = indices.iter().map(|idx| quote! { self.#idx.or(other.#idx) });
+
+    let merge_impl_block = quote! {
+        impl #impl_generics #partial_ident #ty_generics #where_clause {
+            pub fn merge(self, other: #partial_ident #ty_generics) -> Self {
+                Self(#(#merged_fields),*)
+            }
+        }
+    };
+
+    let error_ident = format_ident!("{}Error", partial_ident);
+
+    let error_variants = fields.unnamed.iter().zip(&indices).filter_map(|(f, idx)| {
+        if is_option_type(&f.ty) {
+            return None;
+        }
+        let variant_ident = format_ident!("Field{}Missing", idx.index);
+        let idx_str = idx.index.to_string();
+        Some(quote! {
+            #[error("Field `{}` is missing", #idx_str)]
+            #variant_ident
+        })
+    });
+
+    let construct_fields = fields.unnamed.iter().zip(&indices).map(|(f, idx)| {
+        if is_option_type(&f.ty) {
+            quote! { partial.#idx }
+        } else {
+            let variant_ident = format_ident!("Field{}Missing", idx.index);
+            quote! { partial.#idx.ok_or(#error_ident::#variant_ident)? }
+        }
+    });
+
+    let error_def = quote! {
+        #[derive(::thiserror::Error, Debug)]
+        pub enum #error_ident {
+            #(#error_variants),*
+        }
+    };
+
+    // `#error_ident` can end up uninhabited (e.g. every field is already `Option`), in which case
+    // this impl can never actually fail; that's fine, but it trips clippy's infallible-impl lint.
+    let try_from_impl = quote! {
+        #[allow(clippy::infallible_try_from)]
+        impl #impl_generics ::std::convert::TryFrom<#partial_ident #ty_generics> for #orig_ident #ty_generics #where_clause {
+            type Error = #error_ident;
+
+            fn try_from(partial: #partial_ident #ty_generics) -> ::std::result::Result<#orig_ident #ty_generics, Self::Error> {
+                Ok(#orig_ident(#(#construct_fields),*))
+            }
+        }
+    };
+
+    let field_types: Vec<Type> = fields.unnamed.iter().map(|f| f.ty.clone()).collect();
+
+    let debug_fields = indices.iter().map(|idx| quote! { .field(&self.#idx) });
+    let debug_body = quote! {
+        f.debug_tuple(stringify!(#partial_ident))
+            #(#debug_fields)*
+            .finish()
+    };
+
+    let clone_fields = indices.iter().map(|idx| quote! { self.#idx.clone() });
+    let clone_body = quote! {
+        Self(#(#clone_fields),*)
+    };
+
+    let smart_derive_impls = build_smart_derive_impls(
+        partial_ident,
+        generics,
+        impl_generics,
+        ty_generics,
+        where_clause,
+        &field_types,
+        smart_traits,
+        override_predicates,
+        debug_body,
+        clone_body,
+    );
+
+    quote! {
+        #partial_struct_def
+        #merge_impl_block
+        #error_def
+        #try_from_impl
+        #smart_derive_impls
+    }
+}
+
+/// Unit-struct path: trivially generates an empty partial. There's nothing that can be missing,
+/// so `*PartialError` is an empty (uninhabited) enum and `TryFrom` always succeeds.
+#[allow(clippy::too_many_arguments)]
+fn derive_unit_struct_partial(
+    orig_ident: &Ident,
+    orig_vis: &Visibility,
+    partial_ident: &Ident,
+    generics: &Generics,
+    impl_generics: &ImplGenerics,
+    ty_generics: &TypeGenerics,
+    where_clause: Option<&WhereClause>,
+    maybe_derive_attr: proc_macro2::TokenStream,
+    smart_traits: &[&str],
+    override_predicates: Option<&Punctuated<WherePredicate, Comma>>,
+) -> proc_macro2::TokenStream {
+    let partial_struct_def = quote! {
+        #maybe_derive_attr
+        #orig_vis struct #partial_ident #generics #where_clause;
+    };
+
+    let merge_impl_block = quote! {
+        impl #impl_generics #partial_ident #ty_generics #where_clause {
+            pub fn merge(self, _other: #partial_ident #ty_generics) -> Self {
+                self
+            }
+        }
+    };
+
+    let error_ident = format_ident!("{}Error", partial_ident);
+
+    let error_def = quote! {
+        #[derive(::thiserror::Error, Debug)]
+        pub enum #error_ident {}
+    };
+
+    // The error type is uninhabited, so this `TryFrom` can never actually fail; that's the point
+    // of a unit struct's partial, but it does trip clippy's infallible-impl lint.
+    let try_from_impl = quote! {
+        #[allow(clippy::infallible_try_from)]
+        impl #impl_generics ::std::convert::TryFrom<#partial_ident #ty_generics> for #orig_ident #ty_generics #where_clause {
+            type Error = #error_ident;
+
+            fn try_from(_partial: #partial_ident #ty_generics) -> ::std::result::Result<#orig_ident #ty_generics, Self::Error> {
+                Ok(#orig_ident)
+            }
+        }
+    };
+
+    let debug_body = quote! { f.write_str(stringify!(#partial_ident)) };
+    let clone_body = quote! { Self };
+
+    let smart_derive_impls = build_smart_derive_impls(
+        partial_ident,
+        generics,
+        impl_generics,
+        ty_generics,
+        where_clause,
+        &[],
+        smart_traits,
+        override_predicates,
+        debug_body,
+        clone_body,
+    );
+
+    quote! {
+        #partial_struct_def
+        #merge_impl_block
+        #error_def
+        #try_from_impl
+        #smart_derive_impls
+    }
+}
+
+/// Enum path: each variant's fields are independently wrapped in `Option<T>`, mirroring the
+/// variant's own shape (named/tuple/unit). `merge` combines two values of the *same* variant
+/// field-by-field; across differing variants `self` wins (mirroring `Option::or`'s
+/// first-present-wins behaviour). `TryFrom` fails if the partial's variant has any field missing.
+#[allow(clippy::too_many_arguments)]
+fn derive_enum_partial(
+    orig_ident: &Ident,
+    orig_vis: &Visibility,
+    partial_ident: &Ident,
+    data_enum: &syn::DataEnum,
+    generics: &Generics,
+    impl_generics: &ImplGenerics,
+    ty_generics: &TypeGenerics,
+    where_clause: Option<&WhereClause>,
+    maybe_derive_attr: proc_macro2::TokenStream,
+    smart_traits: &[&str],
+    override_predicates: Option<&Punctuated<WherePredicate, Comma>>,
+) -> proc_macro2::TokenStream {
+    let error_ident = format_ident!("{}Error", partial_ident);
+
+    let variant_defs = data_enum.variants.iter().map(|variant| {
+        let v_ident = &variant.ident;
+        match &variant.fields {
+            Fields::Named(named) => {
+                let fs = named.named.iter().map(|f| {
+                    let f_vis = &f.vis;
+                    let f_ident = f.ident.as_ref().expect("Optifier: Named field must have ident");
+                    let f_ty = &f.ty;
+                    if is_option_type(f_ty) {
+                        quote! { #f_vis #f_ident: #f_ty }
+                    } else {
+                        quote! { #f_vis #f_ident: ::std::option::Option<#f_ty> }
+                    }
+                });
+                quote! { #v_ident { #(#fs),* } }
+            }
+            Fields::Unnamed(unnamed) => {
+                let fs = unnamed.unnamed.iter().map(|f| {
+                    let f_ty = &f.ty;
+                    if is_option_type(f_ty) {
+                        quote! { #f_ty }
+                    } else {
+                        quote! { ::std::option::Option<#f_ty> }
+                    }
+                });
+                quote! { #v_ident(#(#fs),*) }
+            }
+            Fields::Unit => quote! { #v_ident },
+        }
+    });
+
+    let partial_enum_def = quote! {
+        #maybe_derive_attr
+        #orig_vis enum #partial_ident #generics #where_clause {
+            #(#variant_defs),*
+        }
+    };
+
+    let merge_arms = data_enum.variants.iter().map(|variant| {
+        let v_ident = &variant.ident;
+        match &variant.fields {
+            Fields::Named(named) => {
+                let names: Vec<&Ident> = named
+                    .named
+                    .iter()
+                    .map(|f| f.ident.as_ref().expect("Optifier: Named field must have ident"))
+                    .collect();
+                let other_names: Vec<Ident> =
+                    names.iter().map(|n| format_ident!("__other_{}", n)).collect();
+                quote! {
+                    (#partial_ident::#v_ident { #(#names),* }, #partial_ident::#v_ident { #(#names: #other_names),* }) => {
+                        #partial_ident::#v_ident { #(#names: #names.or(#other_names)),* }
+                    }
+                }
+            }
+            Fields::Unnamed(unnamed) => {
+                let self_binds: Vec<Ident> = (0..unnamed.unnamed.len())
+                    .map(|idx| format_ident!("__self_{}", idx))
+                    .collect();
+                let other_binds: Vec<Ident> = (0..unnamed.unnamed.len())
+                    .map(|idx| format_ident!("__other_{}", idx))
+                    .collect();
+                quote! {
+                    (#partial_ident::#v_ident(#(#self_binds),*), #partial_ident::#v_ident(#(#other_binds),*)) => {
+                        #partial_ident::#v_ident(#(#self_binds.or(#other_binds)),*)
+                    }
+                }
+            }
+            Fields::Unit => quote! {
+                (#partial_ident::#v_ident, #partial_ident::#v_ident) => #partial_ident::#v_ident
+            },
+        }
+    });
+
+    let merge_impl_block = quote! {
+        impl #impl_generics #partial_ident #ty_generics #where_clause {
+            pub fn merge(self, other: #partial_ident #ty_generics) -> Self {
+                match (self, other) {
+                    #(#merge_arms),*,
+                    (this, _other) => this,
+                }
+            }
+        }
+    };
+
+    let error_variants = data_enum.variants.iter().flat_map(|variant| {
+        let v_name = variant.ident.to_string();
+        match &variant.fields {
+            Fields::Named(named) => named
+                .named
+                .iter()
+                .filter(|f| !is_option_type(&f.ty))
+                .map(|f| {
+                    let f_ident = f.ident.as_ref().expect("Optifier: Named field must have ident");
+                    let f_pascal = f_ident.to_string().to_case(Case::Pascal);
+                    let variant_ident = format_ident!("{}{}Missing", v_name, f_pascal);
+                    let message = format!("Field `{}::{}` is missing", v_name, f_ident);
+                    quote! {
+                        #[error(#message)]
+                        #variant_ident
+                    }
+                })
+                .collect::<Vec<_>>(),
+            Fields::Unnamed(unnamed) => unnamed
+                .unnamed
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| !is_option_type(&f.ty))
+                .map(|(idx, _)| {
+                    let variant_ident = format_ident!("{}Field{}Missing", v_name, idx);
+                    let message = format!("Field `{}::{}` is missing", v_name, idx);
+                    quote! {
+                        #[error(#message)]
+                        #variant_ident
+                    }
+                })
+                .collect::<Vec<_>>(),
+            Fields::Unit => Vec::new(),
+        }
+    });
+
+    let error_def = quote! {
+        #[derive(::thiserror::Error, Debug)]
+        pub enum #error_ident {
+            #(#error_variants),*
+        }
+    };
+
+    let tryfrom_arms = data_enum.variants.iter().map(|variant| {
+        let v_ident = &variant.ident;
+        let v_name = variant.ident.to_string();
+        match &variant.fields {
+            Fields::Named(named) => {
+                let names: Vec<&Ident> = named
+                    .named
+                    .iter()
+                    .map(|f| f.ident.as_ref().expect("Optifier: Named field must have ident"))
+                    .collect();
+                let construct_fields = named.named.iter().map(|f| {
+                    let f_ident = f.ident.as_ref().expect("Optifier: Named field must have ident");
+                    if is_option_type(&f.ty) {
+                        quote! { #f_ident: #f_ident }
+                    } else {
+                        let f_pascal = f_ident.to_string().to_case(Case::Pascal);
+                        let variant_ident = format_ident!("{}{}Missing", v_name, f_pascal);
+                        quote! { #f_ident: #f_ident.ok_or(#error_ident::#variant_ident)? }
+                    }
+                });
+                quote! {
+                    #partial_ident::#v_ident { #(#names),* } => #orig_ident::#v_ident { #(#construct_fields),* }
+                }
+            }
+            Fields::Unnamed(unnamed) => {
+                let binds: Vec<Ident> = (0..unnamed.unnamed.len())
+                    .map(|idx| format_ident!("__f{}", idx))
+                    .collect();
+                let construct_fields = unnamed.unnamed.iter().zip(&binds).enumerate().map(
+                    |(idx, (f, bind))| {
+                        if is_option_type(&f.ty) {
+                            quote! { #bind }
+                        } else {
+                            let variant_ident = format_ident!("{}Field{}Missing", v_name, idx);
+                            quote! { #bind.ok_or(#error_ident::#variant_ident)? }
+                        }
+                    },
+                );
+                quote! {
+                    #partial_ident::#v_ident(#(#binds),*) => #orig_ident::#v_ident(#(#construct_fields),*)
+                }
+            }
+            Fields::Unit => quote! {
+                #partial_ident::#v_ident => #orig_ident::#v_ident
+            },
+        }
+    });
+
+    // `#error_ident` can end up uninhabited (e.g. every variant is unit or has only `Option`
+    // fields), in which case this impl can never actually fail; that's fine, but it trips
+    // clippy's infallible-impl lint.
+    let try_from_impl = quote! {
+        #[allow(clippy::infallible_try_from)]
+        impl #impl_generics ::std::convert::TryFrom<#partial_ident #ty_generics> for #orig_ident #ty_generics #where_clause {
+            type Error = #error_ident;
+
+            fn try_from(partial: #partial_ident #ty_generics) -> ::std::result::Result<#orig_ident #ty_generics, Self::Error> {
+                Ok(match partial {
+                    #(#tryfrom_arms),*
+                })
+            }
+        }
+    };
+
+    let field_types: Vec<Type> = data_enum
+        .variants
+        .iter()
+        .flat_map(|variant| match &variant.fields {
+            Fields::Named(named) => named.named.iter().map(|f| f.ty.clone()).collect::<Vec<_>>(),
+            Fields::Unnamed(unnamed) => {
+                unnamed.unnamed.iter().map(|f| f.ty.clone()).collect::<Vec<_>>()
+            }
+            Fields::Unit => Vec::new(),
+        })
+        .collect();
+
+    let debug_arms = data_enum.variants.iter().map(|variant| {
+        let v_ident = &variant.ident;
+        let v_name = v_ident.to_string();
+        match &variant.fields {
+            Fields::Named(named) => {
+                let names: Vec<&Ident> = named
+                    .named
+                    .iter()
+                    .map(|f| f.ident.as_ref().expect("Optifier: Named field must have ident"))
+                    .collect();
+                let debug_fields = names.iter().map(|n| {
+                    let n_str = n.to_string();
+                    quote! { .field(#n_str, #n) }
+                });
+                quote! {
+                    #partial_ident::#v_ident { #(#names),* } => {
+                        f.debug_struct(#v_name) #(#debug_fields)* .finish()
+                    }
+                }
+            }
+            Fields::Unnamed(unnamed) => {
+                let binds: Vec<Ident> = (0..unnamed.unnamed.len())
+                    .map(|idx| format_ident!("__f{}", idx))
+                    .collect();
+                let debug_fields = binds.iter().map(|b| quote! { .field(#b) });
+                quote! {
+                    #partial_ident::#v_ident(#(#binds),*) => {
+                        f.debug_tuple(#v_name) #(#debug_fields)* .finish()
+                    }
+                }
+            }
+            Fields::Unit => quote! {
+                #partial_ident::#v_ident => f.write_str(#v_name)
+            },
+        }
+    });
+    let debug_body = quote! {
+        match self {
+            #(#debug_arms),*
+        }
+    };
+
+    let clone_arms = data_enum.variants.iter().map(|variant| {
+        let v_ident = &variant.ident;
+        match &variant.fields {
+            Fields::Named(named) => {
+                let names: Vec<&Ident> = named
+                    .named
+                    .iter()
+                    .map(|f| f.ident.as_ref().expect("Optifier: Named field must have ident"))
+                    .collect();
+                let clone_fields = names.iter().map(|n| quote! { #n: #n.clone() });
+                quote! {
+                    #partial_ident::#v_ident { #(#names),* } => {
+                        #partial_ident::#v_ident { #(#clone_fields),* }
+                    }
+                }
+            }
+            Fields::Unnamed(unnamed) => {
+                let binds: Vec<Ident> = (0..unnamed.unnamed.len())
+                    .map(|idx| format_ident!("__f{}", idx))
+                    .collect();
+                let clone_fields = binds.iter().map(|b| quote! { #b.clone() });
+                quote! {
+                    #partial_ident::#v_ident(#(#binds),*) => {
+                        #partial_ident::#v_ident(#(#clone_fields),*)
+                    }
+                }
+            }
+            Fields::Unit => quote! {
+                #partial_ident::#v_ident => #partial_ident::#v_ident
+            },
+        }
+    });
+    let clone_body = quote! {
+        match self {
+            #(#clone_arms),*
+        }
+    };
+
+    let smart_derive_impls = build_smart_derive_impls(
+        partial_ident,
+        generics,
+        impl_generics,
+        ty_generics,
+        where_clause,
+        &field_types,
+        smart_traits,
+        override_predicates,
+        debug_body,
+        clone_body,
+    );
+
+    quote! {
+        #partial_enum_def
+        #merge_impl_block
+        #error_def
+        #try_from_impl
+        #smart_derive_impls
+    }
+}
@@ -0,0 +1,54 @@
+use optifier::Partial;
+
+#[derive(Partial, Debug)]
+struct Contact {
+    name: String,
+    nickname: Option<String>,
+    age: u8,
+}
+
+#[test]
+fn builder_fills_mixed_option_and_non_option_fields() {
+    let partial = ContactPartial::new().name("Ada".to_string()).age(36);
+
+    let contact: Contact = partial.try_into().unwrap();
+    assert_eq!(contact.name, "Ada");
+    assert_eq!(contact.nickname, None);
+    assert_eq!(contact.age, 36);
+}
+
+#[test]
+fn builder_accepts_unwrapped_value_for_option_field() {
+    let partial = ContactPartial::new().name("Ada".to_string()).nickname("Lovelace".to_string()).age(36);
+
+    let contact: Contact = partial.try_into().unwrap();
+    assert_eq!(contact.nickname, Some("Lovelace".to_string()));
+}
+
+#[test]
+fn builder_errors_when_required_field_is_missing() {
+    let partial = ContactPartial::new().age(36);
+    let err = Contact::try_from(partial).unwrap_err();
+    assert_eq!(err.to_string(), "Field `name` is missing");
+}
+
+#[derive(Partial, Debug)]
+struct Wrapper<T> {
+    value: T,
+    label: Option<String>,
+}
+
+#[test]
+fn builder_works_for_generic_struct() {
+    let partial = WrapperPartial::<i32>::new().value(7);
+    let wrapper: Wrapper<i32> = partial.try_into().unwrap();
+    assert_eq!(wrapper.value, 7);
+    assert_eq!(wrapper.label, None);
+}
+
+#[test]
+fn builder_errors_for_generic_struct_when_value_missing() {
+    let partial = WrapperPartial::<i32>::new().label("tag".to_string());
+    let err = Wrapper::<i32>::try_from(partial).unwrap_err();
+    assert_eq!(err.to_string(), "Field `value` is missing");
+}
@@ -0,0 +1,96 @@
+use optifier::Partial;
+
+#[derive(Default, Debug)]
+struct Cache(Vec<u8>);
+
+#[derive(Partial, Debug)]
+struct SkipNonDefault {
+    #[optifier(skip)]
+    cache: Cache,
+    name: String,
+}
+
+#[test]
+fn skip_field_has_no_setter_and_defaults_on_conversion() {
+    let partial = SkipNonDefaultPartial::new().name("x".to_string());
+    let value: SkipNonDefault = partial.try_into().unwrap();
+    assert_eq!(value.name, "x");
+    assert!(value.cache.0.is_empty());
+}
+
+#[derive(Partial, Debug)]
+struct SkipDefault {
+    #[optifier(skip)]
+    count: u32,
+    name: String,
+}
+
+#[test]
+fn skip_field_with_primitive_default_falls_back_to_default_default() {
+    let partial = SkipDefaultPartial::new().name("x".to_string());
+    let value: SkipDefault = partial.try_into().unwrap();
+    assert_eq!(value.count, 0);
+    assert_eq!(value.name, "x");
+}
+
+#[derive(Partial, Debug)]
+struct Svc {
+    #[optifier(rename = "identifier")]
+    id: i32,
+    name: String,
+}
+
+#[test]
+fn renamed_field_is_addressed_by_its_renamed_name_on_the_partial() {
+    let partial = SvcPartial::new().identifier(7).name("svc".to_string());
+    let svc: Svc = partial.try_into().unwrap();
+    assert_eq!(svc.id, 7);
+    assert_eq!(svc.name, "svc");
+}
+
+#[test]
+fn renamed_field_missing_error_refers_to_the_renamed_name_not_the_original() {
+    let partial = SvcPartial::new().name("svc".to_string());
+    let err = Svc::try_from(partial).unwrap_err();
+    assert_eq!(err.to_string(), "Field `identifier` is missing");
+}
+
+#[derive(Partial, Debug)]
+struct RequiredOption {
+    #[optifier(required)]
+    name: Option<String>,
+}
+
+#[test]
+fn required_on_already_option_field_errors_when_absent() {
+    let partial = RequiredOptionPartial::new();
+    let err = RequiredOption::try_from(partial).unwrap_err();
+    assert_eq!(err.to_string(), "Field `name` is missing");
+}
+
+#[test]
+fn required_on_already_option_field_succeeds_when_present() {
+    let partial = RequiredOptionPartial::new().name("x".to_string());
+    let value: RequiredOption = partial.try_into().unwrap();
+    assert_eq!(value.name, Some("x".to_string()));
+}
+
+#[derive(Partial, Debug)]
+struct WithDefault {
+    #[optifier(default = 8080)]
+    port: u16,
+}
+
+#[test]
+fn default_is_used_when_field_absent() {
+    let partial = WithDefaultPartial::new();
+    let value: WithDefault = partial.try_into().unwrap();
+    assert_eq!(value.port, 8080);
+}
+
+#[test]
+fn default_is_overridden_when_field_present() {
+    let partial = WithDefaultPartial::new().port(9090);
+    let value: WithDefault = partial.try_into().unwrap();
+    assert_eq!(value.port, 9090);
+}
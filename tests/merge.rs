@@ -0,0 +1,102 @@
+use optifier::Partial;
+
+fn max_u16(a: u16, b: u16) -> u16 {
+    a.max(b)
+}
+
+#[derive(Partial, Debug)]
+struct Config {
+    #[optifier(merge = "or")]
+    host: String,
+    #[optifier(merge = "replace")]
+    override_flag: bool,
+    #[optifier(merge = "extend")]
+    tags: Vec<String>,
+    #[optifier(custom = max_u16)]
+    priority: u16,
+}
+
+#[test]
+fn or_keeps_first_present_value() {
+    let a = ConfigPartial::new().host("a.example.com".to_string());
+    let b = ConfigPartial::new().host("b.example.com".to_string());
+
+    let merged = a.merge(b);
+    assert_eq!(merged.host, Some("a.example.com".to_string()));
+}
+
+#[test]
+fn replace_prefers_other_when_present() {
+    let a = ConfigPartial::new().override_flag(true);
+    let b = ConfigPartial::new().override_flag(false);
+
+    let merged = a.merge(b);
+    assert_eq!(merged.override_flag, Some(false));
+}
+
+#[test]
+fn replace_falls_back_to_self_when_other_absent() {
+    let a = ConfigPartial::new().override_flag(true);
+    let b = ConfigPartial::new();
+
+    let merged = a.merge(b);
+    assert_eq!(merged.override_flag, Some(true));
+}
+
+#[test]
+fn extend_concatenates_both_sides() {
+    let a = ConfigPartial::new().tags(vec!["a".to_string()]);
+    let b = ConfigPartial::new().tags(vec!["b".to_string()]);
+
+    let merged = a.merge(b);
+    assert_eq!(merged.tags, Some(vec!["a".to_string(), "b".to_string()]));
+}
+
+#[test]
+fn extend_keeps_whichever_side_is_present_when_other_absent() {
+    let a = ConfigPartial::new().tags(vec!["a".to_string()]);
+    let b = ConfigPartial::new();
+
+    let merged = a.merge(b);
+    assert_eq!(merged.tags, Some(vec!["a".to_string()]));
+}
+
+#[test]
+fn custom_strategy_invokes_merge_function() {
+    let a = ConfigPartial::new().priority(3);
+    let b = ConfigPartial::new().priority(7);
+
+    let merged = a.merge(b);
+    assert_eq!(merged.priority, Some(7));
+}
+
+#[test]
+fn custom_strategy_keeps_whichever_side_is_present_when_other_absent() {
+    let a = ConfigPartial::new().priority(3);
+    let b = ConfigPartial::new();
+
+    let merged = a.merge(b);
+    assert_eq!(merged.priority, Some(3));
+}
+
+#[test]
+fn merge_combines_all_strategies_in_one_struct() {
+    let a = ConfigPartial::new()
+        .host("a.example.com".to_string())
+        .override_flag(true)
+        .tags(vec!["a".to_string()])
+        .priority(3);
+    let b = ConfigPartial::new()
+        .host("b.example.com".to_string())
+        .override_flag(false)
+        .tags(vec!["b".to_string()])
+        .priority(7);
+
+    let merged = a.merge(b);
+    let config: Config = merged.try_into().unwrap();
+
+    assert_eq!(config.host, "a.example.com");
+    assert!(!config.override_flag);
+    assert_eq!(config.tags, vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(config.priority, 7);
+}
@@ -0,0 +1,98 @@
+use optifier::Partial;
+
+#[derive(Partial, Debug)]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[derive(Partial, Debug)]
+struct Base {
+    #[optifier(nested)]
+    server: Server,
+    name: String,
+}
+
+#[test]
+fn nested_builder_takes_inner_partial() {
+    let server = ServerPartial::new().host("localhost".to_string()).port(8080);
+    let partial = BasePartial::new().server(server).name("base".to_string());
+
+    let base: Base = partial.try_into().unwrap();
+    assert_eq!(base.server.host, "localhost");
+    assert_eq!(base.server.port, 8080);
+    assert_eq!(base.name, "base");
+}
+
+#[test]
+fn nested_try_from_bubbles_up_inner_missing_field_as_invalid() {
+    // `host` is missing on the inner partial, so conversion of the outer struct must fail with
+    // the dedicated `ServerInvalid` variant wrapping the inner error, not a generic failure.
+    let server = ServerPartial::new().port(8080);
+    let partial = BasePartial::new().server(server).name("base".to_string());
+
+    let err = Base::try_from(partial).unwrap_err();
+    assert_eq!(err.to_string(), "Field `server` is invalid");
+}
+
+#[test]
+fn nested_field_absent_entirely_is_a_missing_error_not_invalid() {
+    let partial = BasePartial::new().name("base".to_string());
+    let err = Base::try_from(partial).unwrap_err();
+    assert_eq!(err.to_string(), "Field `server` is missing");
+}
+
+#[test]
+fn nested_merge_combines_two_some_values_field_by_field() {
+    let a = BasePartial::new().server(ServerPartial::new().host("localhost".to_string())).name("base".to_string());
+    let b = BasePartial::new().server(ServerPartial::new().port(9090));
+
+    let merged = a.merge(b);
+    let base: Base = merged.try_into().unwrap();
+    assert_eq!(base.server.host, "localhost");
+    assert_eq!(base.server.port, 9090);
+}
+
+#[test]
+fn nested_merge_with_one_side_none_inherits_the_other_side_entirely() {
+    let a = BasePartial::new().name("a".to_string());
+    let b = BasePartial::new().server(ServerPartial::new().host("localhost".to_string()).port(8080)).name("b".to_string());
+
+    let merged = a.merge(b);
+    let base: Base = merged.try_into().unwrap();
+    assert_eq!(base.server.host, "localhost");
+    assert_eq!(base.server.port, 8080);
+    // First-present-wins for the non-nested `name` field.
+    assert_eq!(base.name, "a");
+}
+
+#[derive(Partial, Debug)]
+struct OptionalBase {
+    #[optifier(nested)]
+    server: Option<Server>,
+}
+
+#[test]
+fn nested_field_already_option_on_original_converts_to_none_when_absent() {
+    let partial = OptionalBasePartial::new();
+    let base: OptionalBase = partial.try_into().unwrap();
+    assert!(base.server.is_none());
+}
+
+#[test]
+fn nested_field_already_option_on_original_converts_to_some_when_present_and_valid() {
+    let server = ServerPartial::new().host("localhost".to_string()).port(8080);
+    let partial = OptionalBasePartial::new().server(server);
+
+    let base: OptionalBase = partial.try_into().unwrap();
+    assert_eq!(base.server.unwrap().host, "localhost");
+}
+
+#[test]
+fn nested_field_already_option_on_original_still_bubbles_up_invalid_when_present_but_incomplete() {
+    let server = ServerPartial::new().host("localhost".to_string());
+    let partial = OptionalBasePartial::new().server(server);
+
+    let err = OptionalBase::try_from(partial).unwrap_err();
+    assert_eq!(err.to_string(), "Field `server` is invalid");
+}
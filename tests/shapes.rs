@@ -0,0 +1,87 @@
+use optifier::Partial;
+
+#[derive(Partial, Debug)]
+struct Point(i32, i32);
+
+#[test]
+fn tuple_struct_merges_and_converts() {
+    let a = PointPartial(Some(1), None);
+    let b = PointPartial(None, Some(2));
+
+    let point: Point = a.merge(b).try_into().unwrap();
+    assert_eq!(point.0, 1);
+    assert_eq!(point.1, 2);
+}
+
+#[test]
+fn tuple_struct_errors_on_missing_field() {
+    let partial = PointPartial(Some(1), None);
+    let err = Point::try_from(partial).unwrap_err();
+    assert_eq!(err.to_string(), "Field `1` is missing");
+}
+
+#[derive(Partial, Debug)]
+struct Marker;
+
+#[test]
+fn unit_struct_merges_and_always_converts() {
+    let merged = MarkerPartial.merge(MarkerPartial);
+    let _marker: Marker = merged.try_into().unwrap();
+}
+
+#[derive(Partial, Debug)]
+enum Shape {
+    Circle { radius: f64 },
+    Rectangle(f64, f64),
+    Point,
+}
+
+#[test]
+fn enum_named_variant_merges_and_converts() {
+    let a = ShapePartial::Circle { radius: None };
+    let b = ShapePartial::Circle { radius: Some(2.0) };
+
+    let shape: Shape = a.merge(b).try_into().unwrap();
+    match shape {
+        Shape::Circle { radius } => assert_eq!(radius, 2.0),
+        _ => panic!("expected Circle"),
+    }
+}
+
+#[test]
+fn enum_tuple_variant_merges_and_converts() {
+    let a = ShapePartial::Rectangle(Some(3.0), None);
+    let b = ShapePartial::Rectangle(None, Some(4.0));
+
+    let shape: Shape = a.merge(b).try_into().unwrap();
+    match shape {
+        Shape::Rectangle(w, h) => {
+            assert_eq!(w, 3.0);
+            assert_eq!(h, 4.0);
+        }
+        _ => panic!("expected Rectangle"),
+    }
+}
+
+#[test]
+fn enum_unit_variant_converts() {
+    let partial = ShapePartial::Point;
+    let shape: Shape = partial.try_into().unwrap();
+    assert!(matches!(shape, Shape::Point));
+}
+
+#[test]
+fn enum_mismatched_variants_keep_self_on_merge() {
+    let a = ShapePartial::Point;
+    let b = ShapePartial::Circle { radius: Some(1.0) };
+
+    let merged = a.merge(b);
+    assert!(matches!(merged, ShapePartial::Point));
+}
+
+#[test]
+fn enum_errors_on_missing_field() {
+    let partial = ShapePartial::Circle { radius: None };
+    let err = Shape::try_from(partial).unwrap_err();
+    assert_eq!(err.to_string(), "Field `Circle::radius` is missing");
+}
@@ -0,0 +1,45 @@
+use optifier::Partial;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+/// Deliberately implements neither `Debug` nor `Clone`, to prove a generic parameter that only
+/// ever appears inside `PhantomData<T>` never picks up a bound on the generated partial.
+struct NotDebugNotClone;
+
+#[derive(Partial, Debug)]
+#[partial_derive(Debug, Clone)]
+struct PhantomWrapper<T> {
+    tag: String,
+    _marker: PhantomData<T>,
+}
+
+#[test]
+fn phantom_data_field_does_not_require_debug_or_clone_on_its_type_param() {
+    let partial = PhantomWrapperPartial::<NotDebugNotClone>::new().tag("x".to_string())._marker(PhantomData);
+    let cloned = partial.clone();
+    assert!(format!("{cloned:?}").contains("x"));
+
+    let value: PhantomWrapper<NotDebugNotClone> = cloned.try_into().unwrap();
+    assert_eq!(value.tag, "x");
+}
+
+/// `Rc<T>::clone` never requires `T: Clone`, so the bound that plain field-usage inference would
+/// add (`T: Clone`, since `T` textually appears inside `Option<Rc<T>>`) is spurious/over-strict.
+/// `#[partial_bound(...)]` with no predicates overrides that inferred bound with none at all,
+/// letting a `T` that isn't `Clone` still be used.
+#[derive(Partial)]
+#[partial_derive(Clone)]
+#[partial_bound()]
+struct RcWrapper<T> {
+    inner: Option<Rc<T>>,
+}
+
+#[test]
+fn partial_bound_overrides_an_otherwise_spurious_inferred_clone_bound() {
+    let partial = RcWrapperPartial::<NotDebugNotClone>::new().inner(Rc::new(NotDebugNotClone));
+    let cloned = partial.clone();
+    assert!(Rc::ptr_eq(partial.inner.as_ref().unwrap(), cloned.inner.as_ref().unwrap()));
+
+    let value: RcWrapper<NotDebugNotClone> = partial.try_into().unwrap();
+    assert!(Rc::ptr_eq(&value.inner.unwrap(), cloned.inner.as_ref().unwrap()));
+}